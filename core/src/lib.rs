@@ -0,0 +1,264 @@
+const SEPARATORS: [char; 2] = ['-', ':'];
+
+/// The placeholder token that, inside a variant group, expands to the bare prefix (without a separator).
+const PLACEHOLDER: &str = "~";
+
+/// Splits `str` into its top-level, whitespace-separated items.
+///
+/// Whitespace is only treated as a separator when not nested inside a `(` … `)` variant group or a
+/// `[` … `]` arbitrary value, so brackets containing spaces, commas or parentheses stay intact.
+fn split_top_level(str: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut paren_depth = 0_usize;
+    let mut bracket_depth = 0_usize;
+    let mut start = 0;
+
+    for (i, c) in str.char_indices() {
+        match c {
+            '(' if bracket_depth == 0 => paren_depth += 1,
+            ')' if bracket_depth == 0 => paren_depth = paren_depth.saturating_sub(1),
+            '[' => bracket_depth += 1,
+            ']' => bracket_depth = bracket_depth.saturating_sub(1),
+            c if c.is_whitespace() && paren_depth == 0 && bracket_depth == 0 => {
+                if start < i {
+                    items.push(&str[start..i]);
+                }
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    if start < str.len() {
+        items.push(&str[start..]);
+    }
+
+    items
+}
+
+/// Finds the first top-level `prefix(...)` variant group in `item`, returning the byte ranges of its
+/// prefix and body. The opening `(` is only considered when it is not nested inside a `[` … `]`
+/// arbitrary value (e.g. the `(` in `grid-cols-[repeat(2,1fr)]` is skipped).
+fn find_group(item: &str) -> Option<(usize, usize, usize)> {
+    let mut bracket_depth = 0_usize;
+
+    for (i, c) in item.char_indices() {
+        match c {
+            '[' => bracket_depth += 1,
+            ']' => bracket_depth = bracket_depth.saturating_sub(1),
+            '(' if bracket_depth == 0 => {
+                let open = i;
+                let body_start = i + c.len_utf8();
+                let mut paren_depth = 1_usize;
+                let mut inner_bracket_depth = 0_usize;
+
+                // Scan for the matching `)`, still skipping parens nested inside a `[` … `]` arbitrary
+                // value so bodies such as `a bg-[foo)bar]` are not closed on the bracketed `)`.
+                for (j, c) in item[body_start..].char_indices() {
+                    match c {
+                        '[' => inner_bracket_depth += 1,
+                        ']' => inner_bracket_depth = inner_bracket_depth.saturating_sub(1),
+                        '(' if inner_bracket_depth == 0 => paren_depth += 1,
+                        ')' if inner_bracket_depth == 0 => {
+                            paren_depth -= 1;
+                            if paren_depth == 0 {
+                                return Some((open, body_start, body_start + j));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                return None;
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Prepends `prefix` + `separator` to a single expanded body item, honoring the leading `!` importance
+/// token and the `~` placeholder that stands for "prefix only".
+fn apply_prefix(prefix: &str, separator: char, item: &str) -> String {
+    if item == PLACEHOLDER {
+        return prefix.to_string();
+    }
+
+    if let Some(rest) = item.strip_prefix('!') {
+        format!("!{prefix}{separator}{rest}")
+    } else {
+        format!("{prefix}{separator}{item}")
+    }
+}
+
+/// Recursively expands a single top-level item, returning the flat list of classes it produces.
+fn expand_item(item: &str) -> Vec<String> {
+    let Some((open, body_start, body_end)) = find_group(item) else {
+        return vec![item.to_string()];
+    };
+
+    let prefix = &item[..open];
+    let body = &item[body_start..body_end];
+    let rest = &item[body_end + 1..];
+
+    let separator = prefix.chars().next_back();
+
+    // Only treat this as a variant group when the prefix ends with a separator and the `)` closes the
+    // whole item; anything else is passed through losslessly.
+    match separator {
+        Some(separator) if SEPARATORS.contains(&separator) && rest.is_empty() => {
+            let prefix = &prefix[..prefix.len() - separator.len_utf8()];
+
+            expand(body)
+                .into_iter()
+                .map(|inner| apply_prefix(prefix, separator, &inner))
+                .collect()
+        }
+        _ => vec![item.to_string()],
+    }
+}
+
+/// Expands every top-level item of `str` and collects the resulting classes.
+fn expand(str: &str) -> Vec<String> {
+    split_top_level(str)
+        .into_iter()
+        .flat_map(expand_item)
+        .collect()
+}
+
+/// Returns the byte offset of the first character that breaks the "prefix(sep)(...)" shape
+/// [`expand_item`] requires to treat a group as the entirety of its item: a valid separator before the
+/// `(` and nothing but whitespace after the matching `)`. Finding such an offset means the input holds
+/// a group with a matching `)` that [`transform`] will silently pass through untouched instead of
+/// expanding - e.g. a missing space in `"text-(red sm)font-bold"` - rather than failing loudly.
+///
+/// Items whose first group isn't preceded by a separator aren't flagged: [`expand_item`] already passes
+/// those through losslessly as ordinary text, so there is nothing "dangling" to report.
+pub fn find_dangling_group_suffix(str: &str) -> Option<usize> {
+    find_dangling_group_suffix_in_items(str, 0)
+}
+
+fn find_dangling_group_suffix_in_items(str: &str, base: usize) -> Option<usize> {
+    split_top_level(str)
+        .into_iter()
+        .find_map(|item| find_dangling_group_suffix_in_item(item, base + offset_within(str, item)))
+}
+
+fn find_dangling_group_suffix_in_item(item: &str, item_offset: usize) -> Option<usize> {
+    let (open, body_start, body_end) = find_group(item)?;
+    let prefix = &item[..open];
+    let separator = prefix.chars().next_back();
+
+    if !matches!(separator, Some(separator) if SEPARATORS.contains(&separator)) {
+        return None;
+    }
+
+    let rest = &item[body_end + 1..];
+    if !rest.is_empty() {
+        return Some(item_offset + body_end + 1);
+    }
+
+    find_dangling_group_suffix_in_items(&item[body_start..body_end], item_offset + body_start)
+}
+
+/// The byte offset of the `item` slice within the `parent` slice it was cut from (e.g. by
+/// [`split_top_level`]).
+fn offset_within(parent: &str, item: &str) -> usize {
+    item.as_ptr() as usize - parent.as_ptr() as usize
+}
+
+/// Applies the [Variant Group Transformation](https://github.com/unocss/unocss/tree/main/packages/transformer-variant-group)
+/// to a single class string, expanding groups such as `text-(red sm)` into `text-red text-sm`.
+///
+/// The input is scanned in a single recursive pass that tracks independent depths for `(` … `)` variant
+/// groups and `[` … `]` arbitrary values, so arbitrarily nested groups are expanded and brackets holding
+/// commas, parentheses or spaces (e.g. `grid-cols-[repeat(2,1fr)]`) are left untouched.
+///
+/// This is the shared entry point used by both the compile-time `uno!` macro and, when the `runtime`
+/// feature is enabled, the `to_uno!` macro.
+pub fn transform(str: &str) -> String {
+    expand(str).join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_top_level_keeps_brackets_with_spaces_and_parens_intact() {
+        assert_eq!(
+            split_top_level("grid-cols-[repeat(2,1fr)] bg-red"),
+            vec!["grid-cols-[repeat(2,1fr)]", "bg-red"]
+        );
+    }
+
+    #[test]
+    fn split_top_level_is_not_desynced_by_odd_parens_inside_brackets() {
+        assert_eq!(
+            split_top_level("hover:(content-[')'] bg-red)"),
+            vec!["hover:(content-[')'] bg-red)"]
+        );
+    }
+
+    #[test]
+    fn find_group_skips_parens_nested_inside_brackets() {
+        assert_eq!(find_group("grid-cols-[repeat(2,1fr)]"), None);
+    }
+
+    #[test]
+    fn find_group_skips_a_bracketed_closing_paren_when_locating_the_body_end() {
+        let item = "hover:(content-[')'] bg-red)";
+        let (open, body_start, body_end) = find_group(item).unwrap();
+        assert_eq!(&item[..open], "hover:");
+        assert_eq!(&item[body_start..body_end], "content-[')'] bg-red");
+    }
+
+    #[test]
+    fn transform_expands_a_group_whose_body_holds_a_bracketed_closing_paren() {
+        assert_eq!(
+            transform("hover:(content-[')'] bg-red)"),
+            "hover:content-[')'] hover:bg-red"
+        );
+    }
+
+    #[test]
+    fn transform_leaves_arbitrary_values_with_nested_parens_untouched() {
+        assert_eq!(
+            transform("grid-cols-[repeat(2,1fr)]"),
+            "grid-cols-[repeat(2,1fr)]"
+        );
+    }
+
+    #[test]
+    fn transform_expands_nested_groups() {
+        assert_eq!(
+            transform("placeholder:(italic text-(red sm))"),
+            "placeholder:italic placeholder:text-red placeholder:text-sm"
+        );
+    }
+
+    #[test]
+    fn find_dangling_group_suffix_flags_text_trailing_a_well_formed_group() {
+        let str = "text-(red sm)font-bold";
+        assert_eq!(find_dangling_group_suffix(str), Some(13));
+        assert_eq!(&str[13..], "font-bold");
+    }
+
+    #[test]
+    fn find_dangling_group_suffix_ignores_a_prefix_without_a_separator() {
+        assert_eq!(find_dangling_group_suffix("foo(bar)baz"), None);
+    }
+
+    #[test]
+    fn find_dangling_group_suffix_accepts_a_group_that_closes_its_item() {
+        assert_eq!(find_dangling_group_suffix("text-(red sm) bg-red"), None);
+    }
+
+    #[test]
+    fn find_dangling_group_suffix_recurses_into_a_well_formed_group_body() {
+        let str = "placeholder:(text-(red sm)font-bold)";
+        assert_eq!(find_dangling_group_suffix(str), Some(26));
+        assert_eq!(&str[26..], "font-bold)");
+    }
+}
@@ -0,0 +1,339 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Expr, ExprLit, Lit, LitStr, Token};
+
+use yew_unocss_transformer_core::{find_dangling_group_suffix, transform};
+
+mod manifest;
+
+// Exactly one output backend must be selected. The backends differ only in the final codegen; the
+// parsing and transformation are shared.
+#[cfg(not(any(
+    feature = "yew",
+    feature = "leptos",
+    feature = "dioxus",
+    feature = "string"
+)))]
+compile_error!(
+    "yew-unocss-transformer: no output backend selected; enable one of `yew`, `leptos`, `dioxus` or `string`"
+);
+
+#[cfg(any(
+    all(
+        feature = "yew",
+        any(feature = "leptos", feature = "dioxus", feature = "string")
+    ),
+    all(feature = "leptos", any(feature = "dioxus", feature = "string")),
+    all(feature = "dioxus", feature = "string"),
+))]
+compile_error!(
+    "yew-unocss-transformer: the `yew`, `leptos`, `dioxus` and `string` backends are mutually exclusive; enable exactly one (disable default features to pick a non-Yew backend)"
+);
+
+#[derive(Clone, Debug)]
+struct UnoClassExpr(LitStr);
+
+const ERROR_MSG: &str = "Only string literals are allowed (hint: use classes! macro)";
+
+/// Validates a class literal before transformation, reporting the byte offset (into the literal's
+/// value) of the first malformed construct: unbalanced `(`/`)` or `[`/`]`, an empty variant group, a
+/// `~` placeholder used outside any group, or a well-formed group followed by text instead of
+/// whitespace (e.g. a missing space in `"text-(red sm)font-bold"`, which `transform` would otherwise
+/// pass through unexpanded rather than reject). The offset is later mapped onto a span inside the
+/// literal so the diagnostic points at the exact character.
+fn validate(value: &str) -> Result<(), (usize, &'static str)> {
+    // Each paren on the stack tracks its byte offset and whether a non-whitespace body has been seen,
+    // so empty groups such as `text-()` can be reported.
+    let mut parens: Vec<(usize, bool)> = Vec::new();
+    let mut brackets: Vec<usize> = Vec::new();
+
+    for (i, c) in value.char_indices() {
+        match c {
+            '[' => {
+                if let Some(top) = parens.last_mut() {
+                    top.1 = true;
+                }
+                brackets.push(i);
+            }
+            ']' => {
+                if brackets.pop().is_none() {
+                    return Err((i, "unbalanced `]`"));
+                }
+            }
+            // Inside a `[ … ]` arbitrary value everything is opaque - mirror the parser and skip
+            // paren/`~` accounting so literals such as `content-['(']` are not mis-flagged.
+            _ if !brackets.is_empty() => {}
+            '(' => {
+                if let Some(top) = parens.last_mut() {
+                    top.1 = true;
+                }
+                parens.push((i, false));
+            }
+            ')' => match parens.pop() {
+                Some((offset, false)) => return Err((offset, "empty variant group")),
+                Some(_) => {}
+                None => return Err((i, "unbalanced `)`")),
+            },
+            '~' if parens.is_empty() => {
+                return Err((i, "`~` placeholder is only valid inside a variant group"));
+            }
+            c if c.is_whitespace() => {}
+            _ => {
+                if let Some(top) = parens.last_mut() {
+                    top.1 = true;
+                }
+            }
+        }
+    }
+
+    if let Some(&(offset, _)) = parens.first() {
+        return Err((offset, "unbalanced `(`"));
+    }
+
+    if let Some(&offset) = brackets.first() {
+        return Err((offset, "unbalanced `[`"));
+    }
+
+    if let Some(offset) = find_dangling_group_suffix(value) {
+        return Err((
+            offset,
+            "a variant group's closing `)` must be followed by whitespace or the end of the string \
+             (missing a separating space?)",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Maps a byte `offset` into a plain string literal's value onto a span covering that character, by
+/// offsetting past the opening quote. Falls back to the whole-literal span when the compiler cannot
+/// produce a sub-span (e.g. on stable, where [proc_macro2::Literal::subspan] yields `None`).
+fn token_subspan(lit_str: &LitStr, offset: usize, len: usize) -> proc_macro2::Span {
+    lit_str
+        .token()
+        .subspan(offset + 1..offset + 1 + len)
+        .unwrap_or_else(|| lit_str.span())
+}
+
+impl Parse for UnoClassExpr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        match input.parse()? {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit_str),
+                ..
+            }) => {
+                let value = lit_str.value();
+
+                if let Err((offset, message)) = validate(&value) {
+                    // A sub-span is only accurate for a plain `"…"` literal whose value maps onto the
+                    // source bytes one-to-one after the opening quote. For raw strings (`r"…"`) or any
+                    // literal containing escapes the value offset desyncs from the source byte offset,
+                    // so fall back to the whole-literal span rather than point at the wrong character.
+                    let token = lit_str.token().to_string();
+                    let span = if token.starts_with('"') && token.len() == value.len() + 2 {
+                        // Cover the whole (possibly multi-byte) offending character, not a fixed byte.
+                        let len = value[offset..].chars().next().map_or(1, char::len_utf8);
+                        token_subspan(&lit_str, offset, len)
+                    } else {
+                        lit_str.span()
+                    };
+
+                    return Err(syn::Error::new(span, message));
+                }
+
+                let transformed_value = transform(&value);
+                manifest::record(&transformed_value);
+                let new_lit_str = LitStr::new(&transformed_value, lit_str.span());
+
+                Ok(Self(new_lit_str))
+            }
+            expr => Err(syn::Error::new(expr.span(), ERROR_MSG)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct UnoClasses(Punctuated<UnoClassExpr, Token![,]>);
+
+impl Parse for UnoClasses {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse_terminated(UnoClassExpr::parse).map(Self)
+    }
+}
+
+impl ToTokens for UnoClasses {
+    /// Emits the expanded classes using the backend selected through the `yew`, `leptos`, `dioxus` or
+    /// `string` Cargo features. The `yew` backend builds a
+    /// [Classes](https://docs.rs/yew/latest/yew/html/struct.Classes.html) instance, while the others join the
+    /// expanded utilities into a single space-separated `String`. The parsing and transformation are identical
+    /// across backends; only this final codegen differs.
+    #[cfg(feature = "yew")]
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let n = self.0.len();
+
+        let push_classes = self.0.iter().map(|class_expr| {
+            let UnoClassExpr(class) = class_expr;
+            quote! {
+                __yew_classes.push(#class);
+            }
+        });
+
+        tokens.extend(quote! {
+            {
+                let mut __yew_classes = ::yew::html::Classes::with_capacity(#n);
+                #(#push_classes)*
+                __yew_classes
+            }
+        });
+    }
+
+    #[cfg(any(feature = "leptos", feature = "dioxus"))]
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        // Leptos and Dioxus both take their `class` attribute as an owned `String`.
+        let joined = self.join();
+        tokens.extend(quote! {
+            ::std::string::String::from(#joined)
+        });
+    }
+
+    #[cfg(all(
+        feature = "string",
+        not(any(feature = "yew", feature = "leptos", feature = "dioxus"))
+    ))]
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        // The framework-agnostic fallback emits the joined utilities as an owned `String`.
+        let joined = self.join();
+        tokens.extend(quote! {
+            ::std::string::String::from(#joined)
+        });
+    }
+}
+
+impl UnoClasses {
+    /// Joins every expanded class literal into a single space-separated string. Used by the non-Yew
+    /// backends, whose class type is a plain string rather than a dedicated `Classes` collection.
+    #[cfg(any(feature = "leptos", feature = "dioxus", feature = "string"))]
+    fn join(&self) -> String {
+        self.0
+            .iter()
+            .map(|UnoClassExpr(class)| class.value())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+/// A situational substitute of the [yew::classes!](https://docs.rs/yew/latest/yew/macro.classes.html) macro that additionally
+/// applies [Variant Group Transformation](https://github.com/unocss/unocss/tree/main/packages/transformer-variant-group)
+/// for usage with [UnoCSS](https://github.com/unocss/unocss).
+///
+/// The macro, same as [yew::classes!](https://docs.rs/yew/latest/yew/macro.classes.html), takes a list of items
+/// and returns a [Classes](https://docs.rs/yew/latest/yew/html/struct.Classes.html) instance. With the `leptos`, `dioxus`
+/// or `string` backend it returns the expanded utilities as a single space-separated `String` instead.
+/// Unlike [yew::classes!](https://docs.rs/yew/latest/yew/macro.classes.html), [uno!](#) does not enforce using a single class
+/// per string (e.g. `uno!("text-blue fw800")` works just fine).
+/// The items, however, must be all string literals - other types cannot be transformed anyway.
+///
+/// If you need to transform values that are only known at runtime (e.g. assembled from component props), enable the
+/// `runtime` feature and reach for the `to_uno!` macro instead.
+///
+/// You should use the macro only for [UnoCSS](https://github.com/unocss/unocss) utils. For dynamic classes you should stick
+/// with the classic [yew::classes!](https://docs.rs/yew/latest/yew/macro.classes.html) macro and expand
+/// [UnoCSS safelist](https://github.com/unocss/unocss#safelist), if necessary.
+///
+/// The transformation is executed Rust-side and allows HTML elements with valid classes to be generated. **`.rs` files are not
+/// however parsed correctly by [UnoCSS](https://github.com/unocss/unocss) by default**. Use this macro along with
+/// [unocss-preset-yew](https://www.npmjs.com/package/unocss-preset-yew) so CSS classes can be generated from Rust codebase.
+///
+/// # Example
+///
+/// ```
+/// use yew_unocss_transformer::uno;
+///
+/// assert_eq!(uno!("text-red"), yew::classes!("text-red"));
+///
+/// assert_eq!(uno!("text-(red sm)"), yew::classes!("text-red", "text-sm"));
+///
+/// assert_eq!(
+///     uno!("text-(blue lg)", "placeholder:(italic text-(red sm))"),
+///     yew::classes!(
+///         "text-blue",
+///         "text-lg",
+///         "placeholder:italic",
+///         "placeholder:text-red",
+///         "placeholder:text-sm"
+///     )
+/// );
+///
+/// let dynamic_classes_from_vector = vec!["my-simple-button", "my-simple-button--disabled"];
+/// assert_eq!(
+///     yew::classes!(dynamic_classes_from_vector.clone(), uno!("text-(red sm)")),
+///     yew::classes!(dynamic_classes_from_vector.clone(), "text-red", "text-sm")
+/// );
+/// ```
+#[proc_macro]
+pub fn uno(input: TokenStream) -> TokenStream {
+    let classes = parse_macro_input!(input as UnoClasses);
+    TokenStream::from(classes.into_token_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_accepts_nested_parens_inside_an_arbitrary_value() {
+        assert_eq!(validate("grid-cols-[repeat(2,1fr)]"), Ok(()));
+    }
+
+    #[test]
+    fn validate_accepts_a_bracketed_closing_paren_inside_a_group() {
+        assert_eq!(validate("hover:(content-[')'] bg-red)"), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_unbalanced_opening_paren() {
+        assert_eq!(validate("hover:(bg-red"), Err((6, "unbalanced `(`")));
+    }
+
+    #[test]
+    fn validate_rejects_unbalanced_closing_paren() {
+        assert_eq!(validate("text-red)"), Err((8, "unbalanced `)`")));
+    }
+
+    #[test]
+    fn validate_rejects_empty_groups() {
+        assert_eq!(validate("text-()"), Err((5, "empty variant group")));
+    }
+
+    #[test]
+    fn validate_rejects_text_trailing_a_well_formed_group() {
+        let (offset, message) = validate("text-(red sm)font-bold").unwrap_err();
+        assert_eq!(offset, 13);
+        assert!(message.contains("missing a separating space"));
+    }
+
+    #[test]
+    fn validate_accepts_a_group_followed_by_whitespace() {
+        assert_eq!(validate("text-(red sm) bg-red"), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_the_placeholder_outside_any_group() {
+        assert_eq!(
+            validate("~bg-red"),
+            Err((0, "`~` placeholder is only valid inside a variant group"))
+        );
+    }
+
+    #[test]
+    fn validate_rejects_unbalanced_bracket() {
+        assert_eq!(
+            validate("grid-cols-[repeat(2,1fr)"),
+            Err((10, "unbalanced `[`"))
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::BTreeSet;
+use std::fs::OpenOptions;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+/// Environment variable pointing at the sidecar manifest file. When set, every fully expanded class
+/// string is appended to it so [UnoCSS](https://github.com/unocss/unocss) can extract the utilities
+/// without the `unocss-preset-yew` npm preset.
+const MANIFEST_ENV: &str = "YEW_UNOCSS_MANIFEST";
+
+/// Merges the whitespace-separated `new` classes into `existing`'s newline-separated entries,
+/// returning the deduplicated, sorted (hence deterministic) manifest contents, one class per line.
+fn merge(existing: &str, new: &str) -> String {
+    let mut entries: BTreeSet<&str> = existing.lines().filter(|l| !l.is_empty()).collect();
+    entries.extend(new.split_whitespace());
+
+    let mut contents = String::new();
+    for entry in &entries {
+        contents.push_str(entry);
+        contents.push('\n');
+    }
+
+    contents
+}
+
+/// Appends the whitespace-separated `classes` to the manifest pointed at by [MANIFEST_ENV], keeping the
+/// file deduplicated and sorted (hence deterministic).
+///
+/// No-op unless the environment variable is set. The whole read-merge-write cycle happens under an
+/// exclusive file lock so concurrent macro invocations across a workspace build cannot clobber each
+/// other. Any I/O failure is silently ignored - a missing manifest entry must never fail compilation.
+pub(crate) fn record(classes: &str) {
+    let Ok(path) = std::env::var(MANIFEST_ENV) else {
+        return;
+    };
+
+    if classes.split_whitespace().next().is_none() {
+        return;
+    }
+
+    let Ok(mut file) = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+    else {
+        return;
+    };
+
+    if file.lock().is_err() {
+        return;
+    }
+
+    let mut existing = String::new();
+    if file.read_to_string(&mut existing).is_ok() {
+        let contents = merge(&existing, classes);
+
+        let _ = file
+            .set_len(0)
+            .and_then(|_| file.seek(SeekFrom::Start(0)))
+            .and_then(|_| file.write_all(contents.as_bytes()));
+    }
+
+    let _ = file.unlock();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_is_a_noop_on_an_empty_manifest_and_no_new_classes() {
+        assert_eq!(merge("", ""), "");
+    }
+
+    #[test]
+    fn merge_appends_new_classes_to_an_empty_manifest() {
+        assert_eq!(merge("", "bg-red text-sm"), "bg-red\ntext-sm\n");
+    }
+
+    #[test]
+    fn merge_deduplicates_classes_already_present() {
+        assert_eq!(merge("bg-red\n", "bg-red text-sm"), "bg-red\ntext-sm\n");
+    }
+
+    #[test]
+    fn merge_sorts_the_combined_entries() {
+        assert_eq!(merge("text-sm\n", "bg-red"), "bg-red\ntext-sm\n");
+    }
+
+    #[test]
+    fn merge_ignores_blank_lines_in_the_existing_manifest() {
+        assert_eq!(merge("bg-red\n\n", "text-sm"), "bg-red\ntext-sm\n");
+    }
+}
@@ -0,0 +1,95 @@
+use yew::html::Classes;
+
+use yew_unocss_transformer_core::transform;
+
+/// Applies the [Variant Group Transformation](https://github.com/unocss/unocss/tree/main/packages/transformer-variant-group)
+/// at runtime to any value convertible via [IntoUnoClasses] (e.g. `String`, `&str`, `Vec<_>` or another
+/// `Classes`) and folds the expanded utilities into a single
+/// [Classes](https://docs.rs/yew/latest/yew/html/struct.Classes.html) instance.
+///
+/// Unlike the compile-time [uno!](crate::uno) macro, this accepts dynamic expressions. The raw class
+/// strings are transformed *before* being folded into `Classes`, so groups assembled from component
+/// props such as `"hover:(bg-gray-400 font-medium)"` - which contain spaces and would otherwise be
+/// shredded by `Classes`' whitespace splitting - are expanded just the same.
+///
+/// This function backs the [to_uno!](crate::to_uno) macro and is only available when the `runtime`
+/// feature is enabled.
+pub fn to_uno(source: impl IntoUnoClasses) -> Classes {
+    source.into_uno_classes()
+}
+
+/// Conversion into [Classes](https://docs.rs/yew/latest/yew/html/struct.Classes.html) that runs
+/// [transform] on every raw class string first, so variant groups containing spaces in a `&str` or
+/// `String` survive. A `Classes` source has already been split at whitespace, so only its groups
+/// without spaces (e.g. `text-(red)`) are expanded.
+///
+/// This is only available when the `runtime` feature is enabled.
+pub trait IntoUnoClasses {
+    /// Transforms the source's class string(s) and collects the expanded utilities.
+    fn into_uno_classes(self) -> Classes;
+}
+
+impl IntoUnoClasses for &str {
+    fn into_uno_classes(self) -> Classes {
+        let mut classes = Classes::new();
+        classes.push(transform(self));
+        classes
+    }
+}
+
+impl IntoUnoClasses for String {
+    fn into_uno_classes(self) -> Classes {
+        self.as_str().into_uno_classes()
+    }
+}
+
+impl IntoUnoClasses for Classes {
+    fn into_uno_classes(self) -> Classes {
+        let mut classes = Classes::new();
+        for class in self {
+            classes.push(transform(class.as_ref()));
+        }
+        classes
+    }
+}
+
+impl<T: IntoUnoClasses> IntoUnoClasses for Vec<T> {
+    fn into_uno_classes(self) -> Classes {
+        let mut classes = Classes::new();
+        for item in self {
+            classes.push(item.into_uno_classes());
+        }
+        classes
+    }
+}
+
+/// A runtime counterpart of the [uno!](crate::uno) macro that additionally
+/// applies [Variant Group Transformation](https://github.com/unocss/unocss/tree/main/packages/transformer-variant-group)
+/// to values that are only known at runtime.
+///
+/// The macro takes a list of items - each convertible via [IntoUnoClasses],
+/// e.g. `String`, `&str`, `Vec<_>` or another `Classes` - transforms their class strings and returns a single
+/// [Classes](https://docs.rs/yew/latest/yew/html/struct.Classes.html) instance. It is only available when the `runtime`
+/// feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// use yew_unocss_transformer::to_uno;
+///
+/// let variant = String::from("hover:(bg-gray-400 font-medium)");
+/// assert_eq!(
+///     to_uno!(variant),
+///     yew::classes!("hover:bg-gray-400", "hover:font-medium")
+/// );
+/// ```
+#[macro_export]
+macro_rules! to_uno {
+    ($($class:expr),* $(,)?) => {{
+        let mut __uno_classes = ::yew::html::Classes::new();
+        $(
+            __uno_classes.push($crate::to_uno($class));
+        )*
+        __uno_classes
+    }};
+}